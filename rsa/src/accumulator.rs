@@ -9,15 +9,61 @@ use crate::{
     clone_bignum
 };
 use openssl::bn::*;
+use openssl::hash::{hash, MessageDigest};
+use openssl::nid::Nid;
 use rayon::prelude::*;
 use serde::{Serialize, Deserialize, Serializer, Deserializer, de::{Error as DError, Visitor}};
 use std::{
     convert::TryFrom,
     fmt::Formatter,
+    marker::PhantomData,
     ops::{Add, AddAssign},
     collections::BTreeSet,
 };
 
+/// Maps arbitrary input to the prime number used as an accumulator exponent.
+///
+/// The accumulator is generic over this trait so that applications which must
+/// interoperate with non-OpenSSL stacks, or need a specific domain-separated
+/// digest, can plug in their own mapping while keeping the same prime-membership
+/// semantics. The default is [`DefaultHashToPrime`], matching the behaviour the
+/// crate has always shipped.
+pub trait HashToPrime {
+    /// Deterministically map `value` to a prime `BigNum`
+    fn hash_to_prime<B: AsRef<[u8]>>(value: B) -> BigNum;
+}
+
+/// The mapping used throughout the crate's history, backed by `hash::hash_to_prime`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct DefaultHashToPrime;
+
+impl HashToPrime for DefaultHashToPrime {
+    fn hash_to_prime<B: AsRef<[u8]>>(value: B) -> BigNum {
+        hash_to_prime(value)
+    }
+}
+
+/// A nonce-free mapping following the external RSA-accumulator reference: hash
+/// with Blake2b and, while the candidate is composite, re-hash the previous
+/// digest output until a probable prime is found.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Blake2bHashToPrime;
+
+impl HashToPrime for Blake2bHashToPrime {
+    fn hash_to_prime<B: AsRef<[u8]>>(value: B) -> BigNum {
+        let md = MessageDigest::from_nid(Nid::BLAKE2B512).unwrap();
+        let mut digest = hash(md, value.as_ref()).unwrap().to_vec();
+        let mut ctx = BigNumContext::new().unwrap();
+        loop {
+            let candidate = BigNum::from_slice(&digest).unwrap();
+            if candidate.is_prime(15, &mut ctx).unwrap() {
+                return candidate;
+            }
+            digest = hash(md, &digest).unwrap().to_vec();
+        }
+    }
+}
+
 macro_rules! remove_type {
     ($remove:ident, $remove_mut:ident, $ty:ty) => {
         /// Remove a stringify!($ty) from the accumulator if it exists
@@ -36,7 +82,7 @@ macro_rules! remove_type {
 
 /// Represents a Universal RSA Accumulator.
 #[derive(Debug, Eq, PartialEq)]
-pub struct Accumulator {
+pub struct Accumulator<H: HashToPrime = DefaultHashToPrime> {
     /// The initial value of the accumulator and the generator
     /// to be used for generating proofs
     pub generator: BigNum,
@@ -46,9 +92,11 @@ pub struct Accumulator {
     pub modulus: BigNum,
     /// The current accumulator value with all `members`
     pub value: BigNum,
+    /// The hash-to-prime mapping used for every member
+    _marker: PhantomData<H>,
 }
 
-impl Accumulator {
+impl<H: HashToPrime> Accumulator<H> {
     /// Create a new accumulator
     pub fn new(key: &AccumulatorSecretKey) -> Self {
         let modulus = key.modulus();
@@ -58,7 +106,8 @@ impl Accumulator {
             generator,
             members: BTreeSet::new(),
             modulus,
-            value
+            value,
+            _marker: PhantomData,
         }
     }
 
@@ -66,7 +115,7 @@ impl Accumulator {
     pub fn with_members<M: AsRef<[B]>, B: AsRef<[u8]>>(key: &AccumulatorSecretKey, m: M) -> Self {
         let modulus = key.modulus();
         let m: Vec<&[u8]> = m.as_ref().iter().map(|b| b.as_ref()).collect();
-        let members: BTreeSet<BigNum> = m.par_iter().map(|b| hash_to_prime(b)).collect();
+        let members: BTreeSet<BigNum> = m.par_iter().map(|b| H::hash_to_prime(b)).collect();
         let totient = key.totient();
 
         // From section 3.2 in https://cs.brown.edu/people/alysyans/papers/camlys02.pdf
@@ -90,7 +139,8 @@ impl Accumulator {
             generator,
             members,
             modulus,
-            value
+            value,
+            _marker: PhantomData,
         }
     }
 
@@ -103,7 +153,7 @@ impl Accumulator {
 
     /// Add a value an update this accumulator
     pub fn insert_mut<B: AsRef<[u8]>>(&mut self, value: B) -> Result<(), AccumulatorError> {
-        let p = hash_to_prime(value);
+        let p = H::hash_to_prime(value);
         if self.members.contains(&p) {
             return Err(AccumulatorErrorKind::DuplicateValueSupplied.into());
         }
@@ -125,7 +175,7 @@ impl Accumulator {
 
     /// Remove a value from the accumulator if it exists
     pub fn remove_mut<B: AsRef<[u8]>>(&mut self, key: &AccumulatorSecretKey, value: B) -> Result<(), AccumulatorError> {
-        let v = hash_to_prime(value);
+        let v = H::hash_to_prime(value);
         if !self.members.contains(&v) {
             return Err(AccumulatorErrorKind::InvalidMemberSupplied.into());
         }
@@ -140,6 +190,91 @@ impl Accumulator {
         Ok(())
     }
 
+    /// Create a witness that `value` is a member of this accumulator.
+    ///
+    /// The witness is `g^{∏ members \ x} mod N` where `x = hash_to_prime(value)`,
+    /// i.e. the accumulator value with `x` divided out of the exponent. This path
+    /// does not need the secret key but costs one exponentiation over the product
+    /// of every other member; prefer `prove_membership_with_key` when the key is held.
+    pub fn prove_membership<B: AsRef<[u8]>>(&self, value: B) -> Result<MembershipWitness, AccumulatorError> {
+        let x = H::hash_to_prime(value);
+        if !self.members.contains(&x) {
+            return Err(AccumulatorErrorKind::InvalidMemberSupplied.into());
+        }
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut exp = BigNum::from_u32(1u32).unwrap();
+        for m in &self.members {
+            if m == &x {
+                continue;
+            }
+            let mut t = BigNum::new().unwrap();
+            BigNumRef::mul(&mut t, &exp, m, &mut ctx).unwrap();
+            exp = t;
+        }
+        let mut witness = BigNum::new().unwrap();
+        BigNumRef::mod_exp(&mut witness, &self.generator, &exp, &self.modulus, &mut ctx).unwrap();
+        Ok(MembershipWitness { witness, x, modulus: clone_bignum(&self.modulus) })
+    }
+
+    /// Create a membership witness for `value` using the secret key.
+    ///
+    /// Mirrors `remove_mut`: dividing `x` out of the exponent modulo the totient
+    /// recovers `g^{∏ members \ x}` directly from the accumulator value, so the
+    /// cost is independent of the number of members.
+    pub fn prove_membership_with_key<B: AsRef<[u8]>>(&self, key: &AccumulatorSecretKey, value: B) -> Result<MembershipWitness, AccumulatorError> {
+        let x = H::hash_to_prime(value);
+        if !self.members.contains(&x) {
+            return Err(AccumulatorErrorKind::InvalidMemberSupplied.into());
+        }
+        let t = key.totient();
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut x_1 = BigNum::new().unwrap();
+        BigNumRef::mod_inverse(&mut x_1, &x, &t, &mut ctx).unwrap();
+        let mut witness = BigNum::new().unwrap();
+        BigNumRef::mod_exp(&mut witness, &self.value, &x_1, &self.modulus, &mut ctx).unwrap();
+        Ok(MembershipWitness { witness, x, modulus: clone_bignum(&self.modulus) })
+    }
+
+    /// Create a witness that `value` is not a member of this accumulator.
+    ///
+    /// Computes Bézout coefficients `a, b` with `a * u + b * x = 1`, where `u` is
+    /// the product of every member prime and `x = hash_to_prime(value)`, and
+    /// returns `(a, g^b mod N)`. Errors if `value` is in fact a member.
+    pub fn prove_nonmembership<B: AsRef<[u8]>>(&self, value: B) -> Result<NonMembershipWitness, AccumulatorError> {
+        let x = H::hash_to_prime(value);
+        if self.members.contains(&x) {
+            return Err(AccumulatorErrorKind::InvalidMemberSupplied.into());
+        }
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut u = BigNum::from_u32(1u32).unwrap();
+        for m in &self.members {
+            let mut t = BigNum::new().unwrap();
+            BigNumRef::mul(&mut t, &u, m, &mut ctx).unwrap();
+            u = t;
+        }
+        let (a, b) = bezoute_coefficients(&u, &x);
+        let d = mod_exp_signed(&self.generator, &b, &self.modulus);
+        Ok(NonMembershipWitness { a, d })
+    }
+
+    /// Compute a membership witness for every member in one pass.
+    ///
+    /// Computing witnesses one at a time costs `O(n²)` exponentiations; the
+    /// RootFactor algorithm produces all of them in `O(n log n)`. Witnesses are
+    /// returned aligned to the sorted `members` iteration order.
+    pub fn prove_membership_batch(&self) -> Vec<MembershipWitness> {
+        let members: Vec<BigNum> = self.members.iter().map(clone_bignum).collect();
+        if members.is_empty() {
+            return Vec::new();
+        }
+        let witnesses = root_factor(&self.generator, &members, &self.modulus);
+        members
+            .into_iter()
+            .zip(witnesses)
+            .map(|(x, witness)| MembershipWitness { witness, x, modulus: clone_bignum(&self.modulus) })
+            .collect()
+    }
+
     /// Convert accumulator to bytes
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut out = Vec::with_capacity(MIN_BYTES + MEMBER_SIZE * self.members.len());
@@ -168,7 +303,109 @@ impl Accumulator {
     remove_type!(remove_i8, remove_i8_mut, i8);
 }
 
-impl Clone for Accumulator {
+/// A witness that a value is a member of an `Accumulator`.
+///
+/// The witness for a value `x` is `w = g^{∏ members \ x} mod N`; a verifier is
+/// convinced of membership by checking that `w^x` equals the accumulator value.
+#[derive(Debug, Eq, PartialEq)]
+pub struct MembershipWitness {
+    /// The witness value `g^{∏ members \ x} mod N`
+    pub witness: BigNum,
+    /// The member prime `x = hash_to_prime(value)` this witness attests to
+    pub x: BigNum,
+    /// The RSA modulus the witness is computed under
+    pub modulus: BigNum,
+}
+
+impl MembershipWitness {
+    /// Verify that this witness proves `value` is a member of `accumulator`
+    pub fn verify<H: HashToPrime, B: AsRef<[u8]>>(&self, value: B, accumulator: &Accumulator<H>) -> bool {
+        let x = H::hash_to_prime(value);
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut v = BigNum::new().unwrap();
+        BigNumRef::mod_exp(&mut v, &self.witness, &x, &accumulator.modulus, &mut ctx).unwrap();
+        v == accumulator.value
+    }
+
+    /// Update the witness after `added_value` is inserted into the accumulator.
+    ///
+    /// From Camenisch–Lysyanskaya: on add of prime `y` the new witness is simply
+    /// `w' = w^y mod N`, since every other member now divides the new exponent.
+    pub fn update_on_add<H: HashToPrime, B: AsRef<[u8]>>(&mut self, added_value: B) {
+        let y = H::hash_to_prime(added_value);
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut w = BigNum::new().unwrap();
+        BigNumRef::mod_exp(&mut w, &self.witness, &y, &self.modulus, &mut ctx).unwrap();
+        self.witness = w;
+    }
+
+    /// Update the witness after `removed_value` is removed from the accumulator.
+    ///
+    /// With `c'` the post-removal accumulator value and `y` the removed prime,
+    /// compute Bézout coefficients `a, b` with `a * x + b * y = 1` and set
+    /// `w' = w^b * c'^a mod N`. This satisfies `w'^x = c'` because `c'^y` equals
+    /// the old accumulator value. Errors if `removed_value` is this witness's own value.
+    pub fn update_on_remove<H: HashToPrime, B: AsRef<[u8]>>(&mut self, removed_value: B, new_accumulator: &Accumulator<H>) -> Result<(), AccumulatorError> {
+        let y = H::hash_to_prime(removed_value);
+        if y == self.x {
+            return Err(AccumulatorErrorKind::InvalidMemberSupplied.into());
+        }
+        let (a, b) = bezoute_coefficients(&self.x, &y);
+        let wb = mod_exp_signed(&self.witness, &b, &self.modulus);
+        let ca = mod_exp_signed(&new_accumulator.value, &a, &self.modulus);
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut w = BigNum::new().unwrap();
+        BigNumRef::mod_mul(&mut w, &wb, &ca, &self.modulus, &mut ctx).unwrap();
+        self.witness = w;
+        Ok(())
+    }
+}
+
+impl Clone for MembershipWitness {
+    fn clone(&self) -> Self {
+        Self {
+            witness: clone_bignum(&self.witness),
+            x: clone_bignum(&self.x),
+            modulus: clone_bignum(&self.modulus),
+        }
+    }
+}
+
+/// A witness that a value is **not** a member of an `Accumulator`.
+///
+/// Let `u` be the product of all member primes and `x = hash_to_prime(value)`.
+/// Since every member is prime and distinct from `x`, `gcd(x, u) = 1`, so there
+/// exist integers `a, b` with `a * u + b * x = 1`. The witness is `(a, D)` with
+/// `D = g^b mod N`; verification checks `c^a * D^x ≡ g (mod N)`, which expands to
+/// `g^(a * u + b * x) = g`.
+#[derive(Debug, Eq, PartialEq)]
+pub struct NonMembershipWitness {
+    /// The Bézout coefficient of the member product `u` (may be negative)
+    pub a: BigNum,
+    /// `g^b mod N`, the generator raised to the Bézout coefficient of `x`
+    pub d: BigNum,
+}
+
+impl NonMembershipWitness {
+    /// Verify that this witness proves `value` is not a member of `accumulator`
+    pub fn verify<H: HashToPrime, B: AsRef<[u8]>>(&self, value: B, accumulator: &Accumulator<H>) -> bool {
+        let x = H::hash_to_prime(value);
+        let ca = mod_exp_signed(&accumulator.value, &self.a, &accumulator.modulus);
+        let dx = mod_exp_signed(&self.d, &x, &accumulator.modulus);
+        let mut ctx = BigNumContext::new().unwrap();
+        let mut lhs = BigNum::new().unwrap();
+        BigNumRef::mod_mul(&mut lhs, &ca, &dx, &accumulator.modulus, &mut ctx).unwrap();
+        lhs == accumulator.generator
+    }
+}
+
+impl Clone for NonMembershipWitness {
+    fn clone(&self) -> Self {
+        Self { a: clone_bignum(&self.a), d: clone_bignum(&self.d) }
+    }
+}
+
+impl<H: HashToPrime> Clone for Accumulator<H> {
     fn clone(&self) -> Self {
         let generator = clone_bignum(&self.generator);
         let modulus = clone_bignum(&self.modulus);
@@ -182,12 +419,13 @@ impl Clone for Accumulator {
             generator,
             modulus,
             members,
-            value
+            value,
+            _marker: PhantomData,
         }
     }
 }
 
-impl TryFrom<Vec<u8>> for Accumulator {
+impl<H: HashToPrime> TryFrom<Vec<u8>> for Accumulator<H> {
     type Error = String;
 
     fn try_from(data: Vec<u8>) -> Result<Self, Self::Error> {
@@ -195,7 +433,7 @@ impl TryFrom<Vec<u8>> for Accumulator {
     }
 }
 
-impl TryFrom<&[u8]> for Accumulator {
+impl<H: HashToPrime> TryFrom<&[u8]> for Accumulator<H> {
     type Error = String;
 
     fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
@@ -236,7 +474,8 @@ impl TryFrom<&[u8]> for Accumulator {
             generator,
             members,
             modulus,
-            value
+            value,
+            _marker: PhantomData,
         })
     }
 }
@@ -335,6 +574,106 @@ impl AddAssign<&str> for Accumulator {
     }
 }
 
+/// The product of a slice of big numbers, `∏ xs`.
+fn product(xs: &[BigNum]) -> BigNum {
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut acc = BigNum::from_u32(1u32).unwrap();
+    for x in xs {
+        let mut t = BigNum::new().unwrap();
+        BigNumRef::mul(&mut t, &acc, x, &mut ctx).unwrap();
+        acc = t;
+    }
+    acc
+}
+
+/// RootFactor: given `base` and primes `xs`, return `[base^(∏_{j≠i} x_j)]_i`.
+///
+/// Splitting `xs` into halves `L` and `R`, the witnesses for `L` all contain
+/// `∏ R` in their exponent (and vice-versa), so recursing on `L` with base
+/// `base^(∏ R)` and on `R` with base `base^(∏ L)` yields every witness in
+/// `O(n log n)` exponentiations. The two branches are independent, so they are
+/// run in parallel with rayon as `with_members` does for its batch add.
+fn root_factor(base: &BigNum, xs: &[BigNum], modulus: &BigNum) -> Vec<BigNum> {
+    if xs.len() == 1 {
+        return vec![clone_bignum(base)];
+    }
+    let half = xs.len() / 2;
+    let (left, right) = xs.split_at(half);
+
+    let mut ctx = BigNumContext::new().unwrap();
+    let prod_left = product(left);
+    let prod_right = product(right);
+    let mut base_left = BigNum::new().unwrap();
+    BigNumRef::mod_exp(&mut base_left, base, &prod_right, modulus, &mut ctx).unwrap();
+    let mut base_right = BigNum::new().unwrap();
+    BigNumRef::mod_exp(&mut base_right, base, &prod_left, modulus, &mut ctx).unwrap();
+
+    let (mut l, mut r) = rayon::join(
+        || root_factor(&base_left, left, modulus),
+        || root_factor(&base_right, right, modulus),
+    );
+    l.append(&mut r);
+    l
+}
+
+/// Extended Euclidean algorithm: return `(a, b)` such that
+/// `a * x + b * y = gcd(x, y)`. When `x` and `y` are coprime — as every pair of
+/// distinct member primes is — this yields the Bézout identity `a * x + b * y = 1`.
+fn bezoute_coefficients(x: &BigNum, y: &BigNum) -> (BigNum, BigNum) {
+    let mut ctx = BigNumContext::new().unwrap();
+    let zero = BigNum::from_u32(0u32).unwrap();
+
+    let mut old_r = clone_bignum(x);
+    let mut r = clone_bignum(y);
+    let mut old_s = BigNum::from_u32(1u32).unwrap();
+    let mut s = BigNum::from_u32(0u32).unwrap();
+    let mut old_t = BigNum::from_u32(0u32).unwrap();
+    let mut t = BigNum::from_u32(1u32).unwrap();
+
+    while r != zero {
+        let mut q = BigNum::new().unwrap();
+        let mut rem = BigNum::new().unwrap();
+        BigNumRef::div_rem(&mut q, &mut rem, &old_r, &r, &mut ctx).unwrap();
+
+        old_r = r;
+        r = rem;
+
+        let mut qs = BigNum::new().unwrap();
+        BigNumRef::mul(&mut qs, &q, &s, &mut ctx).unwrap();
+        let mut new_s = BigNum::new().unwrap();
+        BigNumRef::sub(&mut new_s, &old_s, &qs).unwrap();
+        old_s = s;
+        s = new_s;
+
+        let mut qt = BigNum::new().unwrap();
+        BigNumRef::mul(&mut qt, &q, &t, &mut ctx).unwrap();
+        let mut new_t = BigNum::new().unwrap();
+        BigNumRef::sub(&mut new_t, &old_t, &qt).unwrap();
+        old_t = t;
+        t = new_t;
+    }
+
+    (old_s, old_t)
+}
+
+/// `base ^ exp mod modulus` allowing a signed `exp`: a negative exponent is
+/// evaluated as the modular inverse of `base ^ |exp|`.
+fn mod_exp_signed(base: &BigNum, exp: &BigNum, modulus: &BigNum) -> BigNum {
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut result = BigNum::new().unwrap();
+    if exp.is_negative() {
+        let zero = BigNum::from_u32(0u32).unwrap();
+        let mut abs = BigNum::new().unwrap();
+        BigNumRef::sub(&mut abs, &zero, exp).unwrap();
+        let mut pos = BigNum::new().unwrap();
+        BigNumRef::mod_exp(&mut pos, base, &abs, modulus, &mut ctx).unwrap();
+        BigNumRef::mod_inverse(&mut result, &pos, modulus, &mut ctx).unwrap();
+    } else {
+        BigNumRef::mod_exp(&mut result, base, exp, modulus, &mut ctx).unwrap();
+    }
+    result
+}
+
 #[cfg(not(test))]
 fn random_qr(modulus: &BigNum) -> BigNum {
     let mut ctx = BigNumContext::new().unwrap();
@@ -369,7 +708,7 @@ mod tests {
     #[test]
     fn bytes_test() {
         let key = AccumulatorSecretKey::default();
-        let acc = Accumulator::new(&key);
+        let acc: Accumulator = Accumulator::new(&key);
         let bytes = acc.to_bytes();
         assert_eq!(bytes.len(), MIN_BYTES);
         let res = Accumulator::try_from(bytes);
@@ -381,7 +720,7 @@ mod tests {
     #[test]
     fn default_test() {
         let key = AccumulatorSecretKey::default();
-        let acc = Accumulator::new(&key);
+        let acc: Accumulator = Accumulator::new(&key);
         assert_eq!(acc.generator, acc.value);
     }
 
@@ -389,11 +728,11 @@ mod tests {
     fn with_members_test() {
         let members: Vec<[u8; 8]> = vec![3u64.to_be_bytes(), 7u64.to_be_bytes(), 11u64.to_be_bytes(), 13u64.to_be_bytes()];
         let key = AccumulatorSecretKey::default();
-        let mut acc = Accumulator::new(&key);
+        let mut acc: Accumulator = Accumulator::new(&key);
         for m in &members {
             acc.insert_mut(m).unwrap();
         }
-        let acc1 = Accumulator::with_members(&key, members.as_slice());
+        let acc1: Accumulator = Accumulator::with_members(&key, members.as_slice());
         assert_eq!(acc.value, acc1.value);
     }
 
@@ -407,6 +746,94 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn membership_test() {
+        let key = AccumulatorSecretKey::default();
+        let mut acc = Accumulator::new(&key);
+        acc += 3u64;
+        acc += 7u64;
+        let witness = acc.prove_membership(3u64.to_be_bytes()).unwrap();
+        assert!(witness.verify(3u64.to_be_bytes(), &acc));
+        assert!(!witness.verify(7u64.to_be_bytes(), &acc));
+        let witness = acc.prove_membership_with_key(&key, 7u64.to_be_bytes()).unwrap();
+        assert!(witness.verify(7u64.to_be_bytes(), &acc));
+        assert!(acc.prove_membership(11u64.to_be_bytes()).is_err());
+    }
+
+    #[test]
+    fn nonmembership_test() {
+        let key = AccumulatorSecretKey::default();
+        let mut acc = Accumulator::new(&key);
+        acc += 3u64;
+        acc += 7u64;
+        let witness = acc.prove_nonmembership(5u64.to_be_bytes()).unwrap();
+        assert!(witness.verify(5u64.to_be_bytes(), &acc));
+        assert!(acc.prove_nonmembership(3u64.to_be_bytes()).is_err());
+    }
+
+    #[test]
+    fn witness_update_test() {
+        let key = AccumulatorSecretKey::default();
+        let mut acc = Accumulator::new(&key);
+        acc += 3u64;
+        acc += 7u64;
+        let mut witness = acc.prove_membership(3u64.to_be_bytes()).unwrap();
+
+        acc.insert_mut(11u64.to_be_bytes()).unwrap();
+        witness.update_on_add::<DefaultHashToPrime, _>(11u64.to_be_bytes());
+        assert!(witness.verify(3u64.to_be_bytes(), &acc));
+
+        acc.remove_mut(&key, 7u64.to_be_bytes()).unwrap();
+        witness.update_on_remove(7u64.to_be_bytes(), &acc).unwrap();
+        assert!(witness.verify(3u64.to_be_bytes(), &acc));
+
+        assert!(witness.update_on_remove(3u64.to_be_bytes(), &acc).is_err());
+    }
+
+    #[test]
+    fn witness_update_blake2b_test() {
+        let key = AccumulatorSecretKey::default();
+        let mut acc: Accumulator<Blake2bHashToPrime> = Accumulator::new(&key);
+        acc.insert_mut(3u64.to_be_bytes()).unwrap();
+        acc.insert_mut(7u64.to_be_bytes()).unwrap();
+        let mut witness = acc.prove_membership(3u64.to_be_bytes()).unwrap();
+
+        acc.insert_mut(11u64.to_be_bytes()).unwrap();
+        witness.update_on_add::<Blake2bHashToPrime, _>(11u64.to_be_bytes());
+        assert!(witness.verify(3u64.to_be_bytes(), &acc));
+
+        acc.remove_mut(&key, 7u64.to_be_bytes()).unwrap();
+        witness.update_on_remove(7u64.to_be_bytes(), &acc).unwrap();
+        assert!(witness.verify(3u64.to_be_bytes(), &acc));
+    }
+
+    #[test]
+    fn membership_batch_test() {
+        let members: Vec<[u8; 8]> = vec![3u64.to_be_bytes(), 7u64.to_be_bytes(), 11u64.to_be_bytes(), 13u64.to_be_bytes()];
+        let key = AccumulatorSecretKey::default();
+        let acc: Accumulator = Accumulator::with_members(&key, members.as_slice());
+        let witnesses = acc.prove_membership_batch();
+        assert_eq!(witnesses.len(), acc.members.len());
+        let mut ctx = BigNumContext::new().unwrap();
+        for (witness, member) in witnesses.iter().zip(acc.members.iter()) {
+            assert_eq!(&witness.x, member);
+            let mut v = BigNum::new().unwrap();
+            BigNumRef::mod_exp(&mut v, &witness.witness, &witness.x, &acc.modulus, &mut ctx).unwrap();
+            assert_eq!(v, acc.value);
+        }
+    }
+
+    #[test]
+    fn hash_to_prime_blake2b_test() {
+        let key = AccumulatorSecretKey::default();
+        let mut acc: Accumulator<Blake2bHashToPrime> = Accumulator::new(&key);
+        acc.insert_mut(3u64.to_be_bytes()).unwrap();
+        acc.insert_mut(7u64.to_be_bytes()).unwrap();
+        let witness = acc.prove_membership(3u64.to_be_bytes()).unwrap();
+        assert!(witness.verify(3u64.to_be_bytes(), &acc));
+        assert!(!witness.verify(7u64.to_be_bytes(), &acc));
+    }
+
     add_type_test!(add_bignum_test, BigNum::from_dec_str("345_617_283_975_612_837_561_827_365").unwrap());
     add_type_test!(add_string_test, "a test to see if my value is in the accumulator");
     add_type_test!(add_u64_test, 12_345_678_987_654u64);